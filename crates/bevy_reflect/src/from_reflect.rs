@@ -0,0 +1,46 @@
+use crate::{Array, Reflect, ReflectRef};
+use std::convert::TryInto;
+
+/// A [`Reflect`] type that can be reconstructed into a concrete value from a
+/// read-only reference to another (possibly dynamic) [`Reflect`] value.
+///
+/// Unlike [`Reflect::clone_value`], which always produces a dynamic proxy, this
+/// yields a fresh instance of the concrete type, allowing e.g. a deserialized
+/// [`DynamicList`](crate::DynamicList) to be materialized into a `Vec<T>` even
+/// when no existing instance is available to `apply` onto.
+pub trait FromReflect: Reflect + Sized {
+    /// Constructs a concrete instance of `Self` from a reflected value, or
+    /// returns `None` if the conversion failed.
+    fn from_reflect(reflect: &dyn Reflect) -> Option<Self>;
+}
+
+impl<T: FromReflect> FromReflect for Vec<T> {
+    fn from_reflect(reflect: &dyn Reflect) -> Option<Self> {
+        if let ReflectRef::List(ref_list) = reflect.reflect_ref() {
+            let mut values = Vec::with_capacity(ref_list.len());
+            for value in ref_list.iter() {
+                values.push(T::from_reflect(value)?);
+            }
+            Some(values)
+        } else {
+            None
+        }
+    }
+}
+
+impl<T: FromReflect, const N: usize> FromReflect for [T; N] {
+    fn from_reflect(reflect: &dyn Reflect) -> Option<Self> {
+        if let ReflectRef::Array(ref_array) = reflect.reflect_ref() {
+            if ref_array.len() != N {
+                return None;
+            }
+            let mut values = Vec::with_capacity(N);
+            for value in ref_array.iter() {
+                values.push(T::from_reflect(value)?);
+            }
+            values.try_into().ok()
+        } else {
+            None
+        }
+    }
+}
@@ -7,12 +7,153 @@ use crate::{serde::Serializable, Array, ArrayIter, DynamicArray, Reflect, Reflec
 /// size to grow.
 pub trait List: Array {
     fn push(&mut self, value: Box<dyn Reflect>);
+
+    /// Inserts `value` at `index`, shifting later elements to the right.
+    ///
+    /// `insert` and [`remove`](List::remove) are the two structural primitives;
+    /// the remaining edit operations default to them. The default panics so
+    /// that fixed-size implementors need not opt in — growable lists such as
+    /// `Vec<T>` override it.
+    fn insert(&mut self, _index: usize, _value: Box<dyn Reflect>) {
+        panic!("Attempted to insert into a `List` that does not support structural edits.");
+    }
+
+    /// Removes and returns the element at `index`, shifting later elements left.
+    /// Defaults to a panic for the same reason as [`insert`](List::insert).
+    fn remove(&mut self, _index: usize) -> Box<dyn Reflect> {
+        panic!("Attempted to remove from a `List` that does not support structural edits.");
+    }
+
+    fn pop(&mut self) -> Option<Box<dyn Reflect>> {
+        if self.is_empty() {
+            None
+        } else {
+            Some(self.remove(self.len() - 1))
+        }
+    }
+
+    fn clear(&mut self) {
+        while !self.is_empty() {
+            self.remove(self.len() - 1);
+        }
+    }
+
+    fn swap_remove(&mut self, index: usize) -> Box<dyn Reflect> {
+        let last = self.len() - 1;
+        let value = self.remove(index);
+        if index != last {
+            let moved = self.remove(self.len() - 1);
+            self.insert(index, moved);
+        }
+        value
+    }
+
     fn clone_dynamic_list(&self) -> DynamicList {
         DynamicList {
             name: self.type_name().to_string(),
             values: self.iter().map(|value| value.clone_value()).collect(),
         }
     }
+
+    /// Computes an ordered, index-by-index edit set that transforms `self` into
+    /// `other`, or returns `None` if `other` is not a list. Elements are compared
+    /// by position only, so a shift (e.g. one element inserted at the front)
+    /// yields a `SetAt` for every shifted position rather than a single `Insert`.
+    ///
+    /// Elements present in both lists that compare unequal via
+    /// [`Reflect::reflect_partial_eq`] become [`ListDiffOp::SetAt`], trailing
+    /// elements of `other` become [`ListDiffOp::Insert`], and a longer `self`
+    /// collapses into a single [`ListDiffOp::Truncate`]. Replay with
+    /// [`List::apply_diff`].
+    fn reflect_diff(&self, other: &dyn Reflect) -> Option<ListDiff> {
+        let other = if let ReflectRef::List(other) = other.reflect_ref() {
+            other
+        } else {
+            return None;
+        };
+
+        let mut ops = Vec::new();
+        let shared = self.len().min(other.len());
+        for i in 0..shared {
+            let (a, b) = (self.get(i).unwrap(), other.get(i).unwrap());
+            if let Some(false) | None = a.reflect_partial_eq(b) {
+                ops.push(ListDiffOp::SetAt(i, b.clone_value()));
+            }
+        }
+        if other.len() > self.len() {
+            for i in self.len()..other.len() {
+                ops.push(ListDiffOp::Insert(i, other.get(i).unwrap().clone_value()));
+            }
+        } else if self.len() > other.len() {
+            ops.push(ListDiffOp::Truncate(other.len()));
+        }
+
+        Some(ListDiff { ops })
+    }
+
+    /// Replays the operations of `diff` in order, touching only the indices the
+    /// diff references rather than re-applying every element like [`list_apply`].
+    fn apply_diff(&mut self, diff: &ListDiff) {
+        for op in &diff.ops {
+            match op {
+                ListDiffOp::SetAt(index, value) => {
+                    if let Some(slot) = self.get_mut(*index) {
+                        slot.apply(value.as_ref());
+                    }
+                }
+                ListDiffOp::Insert(index, value) => {
+                    self.insert(*index, value.clone_value());
+                }
+                ListDiffOp::Remove(index) => {
+                    self.remove(*index);
+                }
+                ListDiffOp::Truncate(len) => {
+                    while self.len() > *len {
+                        self.pop();
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// A single operation in a [`ListDiff`].
+pub enum ListDiffOp {
+    /// Overwrite the element at the given index with a new value.
+    SetAt(usize, Box<dyn Reflect>),
+    /// Insert a new value at the given index, shifting later elements right.
+    Insert(usize, Box<dyn Reflect>),
+    /// Remove the element at the given index, shifting later elements left.
+    ///
+    /// [`List::reflect_diff`] never produces this variant — it collapses
+    /// trailing target elements into a single [`Truncate`](ListDiffOp::Truncate)
+    /// instead. It exists for hand-built diffs replayed through
+    /// [`List::apply_diff`], so callers matching on [`ListDiff::ops`] should not
+    /// expect it from a computed diff.
+    Remove(usize),
+    /// Drop all elements at or beyond the given length.
+    Truncate(usize),
+}
+
+/// An ordered set of [`ListDiffOp`]s produced by [`List::reflect_diff`].
+#[derive(Default)]
+pub struct ListDiff {
+    ops: Vec<ListDiffOp>,
+}
+
+impl ListDiff {
+    pub fn ops(&self) -> &[ListDiffOp] {
+        &self.ops
+    }
+
+    /// Appends `op` to the end of the diff.
+    pub fn push(&mut self, op: ListDiffOp) {
+        self.ops.push(op);
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.ops.is_empty()
+    }
 }
 
 #[derive(Default)]
@@ -37,6 +178,10 @@ impl DynamicList {
     pub fn push_box(&mut self, value: Box<dyn Reflect>) {
         self.values.push(value);
     }
+
+    pub fn insert_box(&mut self, index: usize, value: Box<dyn Reflect>) {
+        self.values.insert(index, value);
+    }
 }
 
 impl Array for DynamicList {
@@ -76,6 +221,26 @@ impl List for DynamicList {
         DynamicList::push_box(self, value);
     }
 
+    fn insert(&mut self, index: usize, value: Box<dyn Reflect>) {
+        DynamicList::insert_box(self, index, value);
+    }
+
+    fn remove(&mut self, index: usize) -> Box<dyn Reflect> {
+        self.values.remove(index)
+    }
+
+    fn pop(&mut self) -> Option<Box<dyn Reflect>> {
+        self.values.pop()
+    }
+
+    fn clear(&mut self) {
+        self.values.clear();
+    }
+
+    fn swap_remove(&mut self, index: usize) -> Box<dyn Reflect> {
+        self.values.swap_remove(index)
+    }
+
     fn clone_dynamic_list(&self) -> DynamicList {
         DynamicList {
             name: self.name.clone(),
@@ -165,6 +330,9 @@ pub fn list_apply<L: List>(a: &mut L, b: &dyn Reflect) {
                 List::push(a, value.clone_value());
             }
         }
+        while a.len() > list_value.len() {
+            a.pop();
+        }
     } else {
         panic!("Attempted to apply a non-list type to a list type.");
     }
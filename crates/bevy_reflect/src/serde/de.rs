@@ -0,0 +1,173 @@
+use crate::{
+    DynamicArray, DynamicList, Reflect, ReflectDeserialize, TypeRegistration, TypeRegistry,
+};
+use serde::de::{DeserializeSeed, SeqAccess, Visitor};
+use std::fmt;
+
+/// A [`DeserializeSeed`] that turns a serialized sequence into a [`DynamicList`].
+///
+/// Each element is deserialized through the [`ReflectDeserialize`] registered
+/// for `registration`, mirroring the way [`crate::array_serialize`] writes the
+/// list out.
+pub struct ListDeserializer<'a> {
+    registration: &'a TypeRegistration,
+    registry: &'a TypeRegistry,
+}
+
+impl<'a> ListDeserializer<'a> {
+    pub fn new(registration: &'a TypeRegistration, registry: &'a TypeRegistry) -> Self {
+        Self {
+            registration,
+            registry,
+        }
+    }
+}
+
+impl<'a, 'de> DeserializeSeed<'de> for ListDeserializer<'a> {
+    type Value = DynamicList;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        deserializer.deserialize_seq(ListVisitor {
+            element: ElementRegistration {
+                registration: self.registration,
+                registry: self.registry,
+            },
+        })
+    }
+}
+
+struct ListVisitor<'a> {
+    element: ElementRegistration<'a>,
+}
+
+impl<'a, 'de> Visitor<'de> for ListVisitor<'a> {
+    type Value = DynamicList;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("reflected list value")
+    }
+
+    fn visit_seq<V>(self, mut seq: V) -> Result<Self::Value, V::Error>
+    where
+        V: SeqAccess<'de>,
+    {
+        let mut list = DynamicList::default();
+        list.set_name(self.element.registration.name().to_string());
+        while let Some(value) = seq.next_element_seed(ElementDeserializer {
+            element: self.element,
+        })? {
+            list.push_box(value);
+        }
+        Ok(list)
+    }
+}
+
+/// A [`DeserializeSeed`] that turns a serialized sequence into a fixed-capacity
+/// [`DynamicArray`] of exactly `len` elements.
+pub struct ArrayDeserializer<'a> {
+    registration: &'a TypeRegistration,
+    registry: &'a TypeRegistry,
+    len: usize,
+}
+
+impl<'a> ArrayDeserializer<'a> {
+    pub fn new(registration: &'a TypeRegistration, registry: &'a TypeRegistry, len: usize) -> Self {
+        Self {
+            registration,
+            registry,
+            len,
+        }
+    }
+}
+
+impl<'a, 'de> DeserializeSeed<'de> for ArrayDeserializer<'a> {
+    type Value = DynamicArray;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        deserializer.deserialize_seq(ArrayVisitor {
+            element: ElementRegistration {
+                registration: self.registration,
+                registry: self.registry,
+            },
+            len: self.len,
+        })
+    }
+}
+
+struct ArrayVisitor<'a> {
+    element: ElementRegistration<'a>,
+    len: usize,
+}
+
+impl<'a, 'de> Visitor<'de> for ArrayVisitor<'a> {
+    type Value = DynamicArray;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        write!(formatter, "reflected array value of length {}", self.len)
+    }
+
+    fn visit_seq<V>(self, mut seq: V) -> Result<Self::Value, V::Error>
+    where
+        V: SeqAccess<'de>,
+    {
+        let mut values: Vec<Box<dyn Reflect>> = Vec::with_capacity(self.len);
+        while let Some(value) = seq.next_element_seed(ElementDeserializer {
+            element: self.element,
+        })? {
+            if values.len() == self.len {
+                return Err(serde::de::Error::invalid_length(
+                    values.len() + 1,
+                    &self,
+                ));
+            }
+            values.push(value);
+        }
+        if values.len() != self.len {
+            return Err(serde::de::Error::invalid_length(values.len(), &self));
+        }
+        let mut array = DynamicArray::new(values.into_boxed_slice());
+        array.set_name(self.element.registration.name().to_string());
+        Ok(array)
+    }
+}
+
+/// The registered type of the elements of a list or array, together with the
+/// registry needed to resolve any nested reflected types.
+#[derive(Clone, Copy)]
+struct ElementRegistration<'a> {
+    registration: &'a TypeRegistration,
+    registry: &'a TypeRegistry,
+}
+
+/// Deserializes a single element through the [`ReflectDeserialize`] registered
+/// for the element's type.
+struct ElementDeserializer<'a> {
+    element: ElementRegistration<'a>,
+}
+
+impl<'a, 'de> DeserializeSeed<'de> for ElementDeserializer<'a> {
+    type Value = Box<dyn Reflect>;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let registration = self.element.registration;
+        let reflect_deserialize =
+            registration
+                .data::<ReflectDeserialize>(self.element.registry)
+                .ok_or_else(|| {
+                    serde::de::Error::custom(format!(
+                        "type `{}` did not register `ReflectDeserialize`",
+                        registration.name()
+                    ))
+                })?;
+        reflect_deserialize.deserialize(deserializer)
+    }
+}